@@ -0,0 +1,170 @@
+//! 実行時設定 `Config` と、その設定に基づく処理本体 `run`。
+//!
+//! [重要] minigrep チュートリアルの `main`/`run` 分離に倣い、
+//! 「引数を解釈する層」と「実際に挨拶を出力する層」を分けています。
+//! 理由: 入出力を伴う処理を `main` から切り離し、見通しを良くするため。
+
+use crate::error::AppError;
+use crate::flags::{self, optFlag};
+use crate::greeting::{self, Locale};
+
+/// 表示名(name)の取得元。
+#[allow(non_snake_case)]
+#[derive(Debug, Clone)]
+pub enum NameSource {
+    /// コマンドラインで直接指定された単一の名前。
+    Single(String),
+
+    /// `--file` で指定されたファイル。各非空行を名前として扱います。
+    File(String),
+}
+
+/// 挨拶の出力先。
+///
+/// [重要] 現状は標準出力のみですが、将来の拡張（ファイル出力等）に備えて
+/// `Config` の一部として独立させています。
+#[derive(Debug, Clone)]
+pub enum OutputDestination {
+    Stdout,
+}
+
+/// `run` が必要とする実行時設定。
+#[allow(non_snake_case)]
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// 表示名の取得元。
+    pub nameSource: NameSource,
+
+    /// 出力先。
+    pub output: OutputDestination,
+
+    /// 挨拶に使うロケール。
+    pub locale: Locale,
+
+    /// 挨拶を繰り返す回数。
+    pub times: u32,
+}
+
+/// このプログラムが認識するフラグの一覧を返します。
+///
+/// [重要] `--help` 生成と未知フラグ判定の両方がこの一覧を参照します。
+#[allow(non_snake_case)]
+fn registeredFlags() -> Vec<flags::Flag> {
+    vec![
+        optFlag("-n", "--name", "表示する名前を指定します"),
+        optFlag("", "--file", "各行を名前として読み込むファイルを指定します"),
+        optFlag("", "--lang", "挨拶に使うロケールを指定します (en, ja, es, fr)"),
+        optFlag("", "--times", "挨拶を繰り返す回数を指定します (既定値: 1)"),
+        optFlag("-h", "--help", "このヘルプを表示します"),
+    ]
+}
+
+/// `--lang` フラグまたは `LANG` 環境変数からロケールを解決します。
+///
+/// [重要] `--lang` が指定されていればそれを優先し、未指定なら `LANG` 環境変数
+/// （例: `ja_JP.UTF-8`）を参照します。どちらも無ければ `Locale::En` を既定値とします。
+///
+/// ## 引数
+/// - `langCode`: `--lang` で指定されたロケールコード（未指定なら `None`）
+///
+/// ## 戻り値
+/// - `Ok(Locale)`: 解決できたロケール
+/// - `Err(AppError::InvalidLocale)`: 未知のロケールコードが指定された場合
+#[allow(non_snake_case)]
+fn resolveLocale(langCode: Option<String>) -> Result<Locale, AppError> {
+    match langCode.or_else(|| std::env::var("LANG").ok()) {
+        Some(code) => greeting::localeFromCode(&code),
+        None => Ok(Locale::En),
+    }
+}
+
+impl Config {
+    /// コマンドライン引数(UTF-8 済み)から `Config` を組み立てます。
+    ///
+    /// [重要] 実際の引数解析は [`flags::parseArgs`] に委譲します。
+    ///
+    /// ## 引数
+    /// - `args`: `std::env::args_os()` を UTF-8 検証済みにした `Vec<String>`（プログラム名を含む）
+    ///
+    /// ## 戻り値
+    /// - `Ok(Config)`: 組み立てられた設定
+    /// - `Err(AppError)`: 未知のフラグ、name/file/lang の重複指定、name と `--file` の同時指定、
+    ///   未知のロケールコード、または `--times` の値の不正・欠落・重複指定
+    #[allow(non_snake_case)]
+    pub fn build(args: &[String]) -> Result<Config, AppError> {
+        const EXPECTED_USAGE: &str = "name and --file are mutually exclusive; specify only one";
+
+        let parsed = flags::parseArgs(args, &registeredFlags())?;
+
+        // 空文字列・空白のみの name は「未指定」として扱います（baseline の
+        // `parseNameFromArgs` の `!value.trim().is_empty()` 判定を踏襲）。
+        let name = parsed.name.filter(|value| !value.trim().is_empty());
+
+        if name.is_some() && parsed.filePath.is_some() {
+            return Err(AppError::TooManyArguments {
+                functionName: "Config::build",
+                args: args.to_vec(),
+                expectedUsage: EXPECTED_USAGE,
+            });
+        }
+
+        let nameSource = match parsed.filePath {
+            Some(path) => NameSource::File(path),
+            None => NameSource::Single(name.unwrap_or_else(|| "World".to_string())),
+        };
+
+        let locale = resolveLocale(parsed.langCode)?;
+        let times = flags::parseTypedArg::<u32>(args, "--times")?.unwrap_or(1);
+
+        Ok(Config {
+            nameSource,
+            output: OutputDestination::Stdout,
+            locale,
+            times,
+        })
+    }
+}
+
+/// `config` に従って挨拶を出力します。
+///
+/// [重要] `NameSource::File` の場合、空行は無視し、非空行ごとに挨拶を1行出力します。
+/// [重要] `config.times` に従い、名前ごとに挨拶をその回数だけ繰り返します。
+///
+/// ## 引数
+/// - `config`: [`Config::build`] で組み立てた実行時設定
+///
+/// ## 戻り値
+/// - `Ok(())`: 正常終了
+/// - `Err(AppError::FileRead)`: ファイル読み込みエラー
+pub fn run(config: &Config) -> Result<(), AppError> {
+    match &config.nameSource {
+        NameSource::Single(name) => {
+            printGreetingRepeated(name, config.locale, config.times, &config.output);
+        }
+        NameSource::File(path) => {
+            let contents = std::fs::read_to_string(path).map_err(|source| AppError::FileRead {
+                path: path.clone(),
+                source,
+            })?;
+
+            for line in contents.lines() {
+                let trimmed = line.trim();
+                if !trimmed.is_empty() {
+                    printGreetingRepeated(trimmed, config.locale, config.times, &config.output);
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// `output` と `locale` に応じて、`name` の挨拶を `times` 回出力します。
+#[allow(non_snake_case)]
+fn printGreetingRepeated(name: &str, locale: Locale, times: u32, output: &OutputDestination) {
+    for _ in 0..times {
+        match output {
+            OutputDestination::Stdout => println!("{}", greeting::greeting(locale, name)),
+        }
+    }
+}
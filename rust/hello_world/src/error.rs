@@ -0,0 +1,124 @@
+//! このクレート全体で使うエラー型 `AppError`。
+//!
+//! [重要] 失敗理由ごとに異なるメッセージを出せるよう、単一の汎用エラー構造体ではなく
+//! 失敗モードごとの enum variant に分けています。
+//! 理由: 「引数が多すぎる」も「未知のフラグ」も「ファイルが読めない」も同じ文面では、
+//! 利用者が次に何をすべきか分からないため。
+//! [推奨] 各 variant のメッセージは「関数名・期待される形式」を含む詳細なものにします。
+
+use std::error::Error;
+use std::fmt;
+
+/// このクレートで起こりうる失敗をまとめた enum。
+#[allow(non_snake_case)]
+#[derive(Debug)]
+pub enum AppError {
+    /// 引数の形式が仕様に合わない場合（name/file/lang/times の重複指定、値の欠落、
+    /// name と `--file` の同時指定など）。
+    TooManyArguments {
+        /// エラーが発生した関数名。
+        functionName: &'static str,
+
+        /// 受け取った引数一覧（プログラム名を含む）。
+        args: Vec<String>,
+
+        /// 期待していた引数の形式。
+        expectedUsage: &'static str,
+    },
+
+    /// 登録されていないフラグが指定された場合。
+    UnknownFlag {
+        /// 指定された未知のフラグ文字列。
+        flag: String,
+    },
+
+    /// 引数に不正な UTF-8 が含まれていた場合。
+    InvalidUtf8Arg {
+        /// `to_string_lossy` で復元した引数の内容。
+        lossy: String,
+    },
+
+    /// `--lang`/`LANG` で未知のロケールコードが指定された場合。
+    InvalidLocale {
+        /// 指定されたロケールコード。
+        code: String,
+
+        /// サポートするロケールコードの一覧（表示用）。
+        supported: &'static str,
+    },
+
+    /// ファイルの読み込みに失敗した場合。
+    FileRead {
+        /// 読み込もうとしたファイルパス。
+        path: String,
+
+        /// 原因となった I/O エラー。
+        source: std::io::Error,
+    },
+
+    /// 型付きフラグ（例: `--times`）の値が期待する型にパースできなかった場合。
+    InvalidFlagValue {
+        /// 対象のフラグ（例: `"--times"`）。
+        flag: String,
+
+        /// 実際に指定された値。
+        value: String,
+
+        /// 期待する型名（`std::any::type_name` 経由）。
+        expectedType: &'static str,
+    },
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AppError::TooManyArguments { functionName, args, expectedUsage } => write!(
+                f,
+                "Invalid arguments in {functionName}.\n  expected: {expectedUsage}\n  actual args({argc}): {args:?}",
+                functionName = functionName,
+                expectedUsage = expectedUsage,
+                argc = args.len(),
+                args = args
+            ),
+            AppError::UnknownFlag { flag } => write!(
+                f,
+                "Invalid arguments in parseArgs.\n  expected: a registered flag (see --help)\n  actual flag: {flag}",
+                flag = flag
+            ),
+            AppError::InvalidUtf8Arg { lossy } => write!(
+                f,
+                "Invalid arguments in toUtf8Args.\n  expected: UTF-8 encoded arguments\n  actual (lossy): {lossy}",
+                lossy = lossy
+            ),
+            AppError::InvalidLocale { code, supported } => write!(
+                f,
+                "Invalid arguments in localeFromCode.\n  expected: one of [{supported}]\n  actual code: {code}",
+                supported = supported,
+                code = code
+            ),
+            AppError::FileRead { path, source } => match source.kind() {
+                std::io::ErrorKind::NotFound => write!(f, "File not found in Config::build/run: {}", path),
+                std::io::ErrorKind::PermissionDenied => {
+                    write!(f, "Permission denied reading file in Config::build/run: {}", path)
+                }
+                _ => write!(f, "Failed to read file in Config::build/run: {} ({})", path, source),
+            },
+            AppError::InvalidFlagValue { flag, value, expectedType } => write!(
+                f,
+                "Invalid arguments in parseTypedArg.\n  expected: a {expectedType} value for {flag}\n  actual value: {value}",
+                expectedType = expectedType,
+                flag = flag,
+                value = value
+            ),
+        }
+    }
+}
+
+impl Error for AppError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AppError::FileRead { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,255 @@
+//! コマンドライン引数解析サブシステム。
+//!
+//! [重要] 外部コースウェアの `Flag` 構造を参考に、`-n`/`--name`/`--name=<value>` と
+//! 位置引数の両方を受け付ける小さなパーサーを提供します。
+//! 理由: 単一の位置引数だけでは実用的な CLI として物足りないため。
+
+use crate::error::AppError;
+
+/// 1 つのフラグ定義（短縮形・完全形・説明）。
+///
+/// [重要] このリポジトリの命名規則（lowerCamelCase）に合わせてフィールド名を付けています。
+#[allow(non_snake_case)]
+#[derive(Debug, Clone)]
+pub struct Flag {
+    /// 短縮形（例: `-n`）。短縮形を持たないフラグは空文字列にします。
+    pub shortHand: String,
+
+    /// 完全形（例: `--name`）。
+    pub longHand: String,
+
+    /// `--help` に表示する説明文。
+    pub desc: String,
+}
+
+/// `Flag` を組み立てるコンストラクタ。
+///
+/// ## 引数
+/// - `shortHand`: 短縮形（例: `"-n"`）。短縮形がない場合は `""`
+/// - `longHand`: 完全形（例: `"--name"`）
+/// - `desc`: ヘルプに表示する説明
+#[allow(non_snake_case)]
+pub fn optFlag(shortHand: &str, longHand: &str, desc: &str) -> Flag {
+    Flag {
+        shortHand: shortHand.to_string(),
+        longHand: longHand.to_string(),
+        desc: desc.to_string(),
+    }
+}
+
+/// `parseArgs` の解析結果。
+///
+/// [重要] `Config::build` はこの構造体から `Config` を組み立てます。
+#[allow(non_snake_case)]
+#[derive(Debug, Clone)]
+pub struct ParsedArgs {
+    /// 表示名(name)。未指定なら `None`（既定値 "World" への解決は呼び出し側が行います）。
+    pub name: Option<String>,
+
+    /// `--file` で指定されたファイルパス（未指定なら `None`）。
+    pub filePath: Option<String>,
+
+    /// `--lang` で指定されたロケールコード（未指定なら `None`）。
+    pub langCode: Option<String>,
+}
+
+/// 登録された `flags` の説明から `--help` 出力を組み立てます。
+#[allow(non_snake_case)]
+fn formatHelp(flags: &[Flag]) -> String {
+    let mut lines = vec!["Usage: cargo run -- [name] [options]".to_string(), String::new(), "Options:".to_string()];
+
+    for flag in flags {
+        let shortHand = if flag.shortHand.is_empty() {
+            "    ".to_string()
+        } else {
+            format!("{},", flag.shortHand)
+        };
+        lines.push(format!("  {} {:<12} {}", shortHand, flag.longHand, flag.desc));
+    }
+
+    lines.join("\n")
+}
+
+/// `args` を `flags` の定義に従って解析し、表示名(name)とオプション値を取り出します。
+///
+/// [重要] `-n <name>` / `--name <name>` / `--name=<name>` / 位置引数に加え、
+/// `--file <path>` / `--file=<path>` 、`--lang <code>` / `--lang=<code>` も受け付けます。
+/// `--times <n>` のトークンも読み飛ばします（実際の値の型変換は [`parseTypedArg`] が行います）。
+/// `-h`/`--help` が指定された場合はヘルプを標準出力に表示し、プロセスを正常終了します。
+///
+/// ## 引数
+/// - `args`: `std::env::args()` の結果（プログラム名を含む）
+/// - `flags`: `--help` 生成および既知フラグ判定に使う登録済みフラグ一覧
+///
+/// ## 戻り値
+/// - `Ok(ParsedArgs)`: 解析結果
+/// - `Err(AppError::UnknownFlag)`: 登録されていないフラグが指定された場合
+/// - `Err(AppError::TooManyArguments)`: name/file/lang の重複指定や値の欠落
+#[allow(non_snake_case)]
+pub fn parseArgs(args: &[String], flags: &[Flag]) -> Result<ParsedArgs, AppError> {
+    const EXPECTED_USAGE: &str =
+        "cargo run -- [name] [-n|--name <name>] [--file <path>] [--lang <code>] [--times <n>] [-h|--help]";
+
+    let isNameFlag = |candidate: &str| candidate == "-n" || candidate == "--name" || candidate.starts_with("--name=");
+    let isHelpFlag = |candidate: &str| candidate == "-h" || candidate == "--help";
+    let isFileFlag = |candidate: &str| candidate == "--file" || candidate.starts_with("--file=");
+    let isLangFlag = |candidate: &str| candidate == "--lang" || candidate.starts_with("--lang=");
+    let isTimesFlag = |candidate: &str| candidate == "--times" || candidate.starts_with("--times=");
+
+    let invalidArgs = || AppError::TooManyArguments {
+        functionName: "parseArgs",
+        args: args.to_vec(),
+        expectedUsage: EXPECTED_USAGE,
+    };
+
+    let mut name: Option<String> = None;
+    let mut filePath: Option<String> = None;
+    let mut langCode: Option<String> = None;
+    let mut index = 1;
+
+    while index < args.len() {
+        let arg = &args[index];
+
+        // `flags` に登録されていないフラグ（`=` より前の部分で判定）は未知フラグとして扱います。
+        if arg.starts_with('-') {
+            let flagName = arg.split('=').next().unwrap_or("");
+            let isRegistered = flags
+                .iter()
+                .any(|flag| flag.longHand == flagName || (!flag.shortHand.is_empty() && flag.shortHand == flagName));
+            if !isRegistered {
+                return Err(AppError::UnknownFlag { flag: arg.clone() });
+            }
+        }
+
+        if isHelpFlag(arg) {
+            println!("{}", formatHelp(flags));
+            std::process::exit(0);
+        } else if let Some(value) = arg.strip_prefix("--name=") {
+            if name.is_some() {
+                return Err(invalidArgs());
+            }
+            name = Some(value.to_string());
+        } else if let Some(value) = arg.strip_prefix("--file=") {
+            if filePath.is_some() {
+                return Err(invalidArgs());
+            }
+            filePath = Some(value.to_string());
+        } else if let Some(value) = arg.strip_prefix("--lang=") {
+            if langCode.is_some() {
+                return Err(invalidArgs());
+            }
+            langCode = Some(value.to_string());
+        } else if isNameFlag(arg) {
+            let value = args.get(index + 1).ok_or_else(invalidArgs)?;
+
+            if name.is_some() {
+                return Err(invalidArgs());
+            }
+            name = Some(value.clone());
+            index += 1;
+        } else if isFileFlag(arg) {
+            let value = args.get(index + 1).ok_or_else(invalidArgs)?;
+
+            if filePath.is_some() {
+                return Err(invalidArgs());
+            }
+            filePath = Some(value.clone());
+            index += 1;
+        } else if isLangFlag(arg) {
+            let value = args.get(index + 1).ok_or_else(invalidArgs)?;
+
+            if langCode.is_some() {
+                return Err(invalidArgs());
+            }
+            langCode = Some(value.clone());
+            index += 1;
+        } else if isTimesFlag(arg) {
+            // 値自体の型変換(u32への変換)は parseTypedArg が別途行うため、
+            // ここではトークンを読み飛ばして未知フラグ扱いにならないようにするだけです。
+            if arg == "--times" {
+                args.get(index + 1).ok_or_else(invalidArgs)?;
+                index += 1;
+            }
+        } else if arg.starts_with('-') {
+            return Err(AppError::UnknownFlag { flag: arg.clone() });
+        } else {
+            if name.is_some() {
+                return Err(invalidArgs());
+            }
+            name = Some(arg.clone());
+        }
+
+        index += 1;
+    }
+
+    Ok(ParsedArgs {
+        name,
+        filePath,
+        langCode,
+    })
+}
+
+/// `args` の中から `flag <value>` / `flag=<value>` を探し、`T` へパースします。
+///
+/// [重要] FP Complete のクラッシュコースにある width/height 検証の考え方を踏襲し、
+/// 「型変換に失敗したら、フラグ名・実際の値・期待する型を含むエラーを返す」方針にします。
+///
+/// ## 引数
+/// - `args`: `std::env::args()` の結果（プログラム名を含む）
+/// - `flag`: 探索対象のフラグ（例: `"--times"`）
+///
+/// ## 戻り値
+/// - `Ok(None)`: `flag` が指定されていない場合
+/// - `Ok(Some(value))`: `flag` の値を `T` へのパースに成功した場合
+/// - `Err(AppError::InvalidFlagValue)`: 値が `T` にパースできなかった、または値が欠落していた場合
+/// - `Err(AppError::TooManyArguments)`: `flag` が2回以上指定された場合
+#[allow(non_snake_case)]
+pub fn parseTypedArg<T: std::str::FromStr>(args: &[String], flag: &str) -> Result<Option<T>, AppError> {
+    const EXPECTED_USAGE: &str = "each flag may be specified at most once";
+
+    let longPrefix = format!("{}=", flag);
+    let mut found: Option<String> = None;
+    let mut index = 1;
+
+    while index < args.len() {
+        let arg = &args[index];
+
+        if arg == flag {
+            let raw = args.get(index + 1).ok_or_else(|| AppError::InvalidFlagValue {
+                flag: flag.to_string(),
+                value: "<missing>".to_string(),
+                expectedType: std::any::type_name::<T>(),
+            })?;
+
+            if found.is_some() {
+                return Err(AppError::TooManyArguments {
+                    functionName: "parseTypedArg",
+                    args: args.to_vec(),
+                    expectedUsage: EXPECTED_USAGE,
+                });
+            }
+            found = Some(raw.clone());
+            index += 1;
+        } else if let Some(value) = arg.strip_prefix(&longPrefix) {
+            if found.is_some() {
+                return Err(AppError::TooManyArguments {
+                    functionName: "parseTypedArg",
+                    args: args.to_vec(),
+                    expectedUsage: EXPECTED_USAGE,
+                });
+            }
+            found = Some(value.to_string());
+        }
+
+        index += 1;
+    }
+
+    match found {
+        None => Ok(None),
+        Some(raw) => raw.parse::<T>().map(Some).map_err(|_| AppError::InvalidFlagValue {
+            flag: flag.to_string(),
+            value: raw,
+            expectedType: std::any::type_name::<T>(),
+        }),
+    }
+}
@@ -0,0 +1,71 @@
+//! ロケールに応じた挨拶文の生成。
+//!
+//! [重要] これまで "Hello, {name}!" に固定されていた挨拶を、ロケールごとに
+//! 切り替えられるようにします。理由: クレートの制限事項だった i18n 未対応を解消するため。
+
+use crate::error::AppError;
+
+/// サポートするロケール。
+#[allow(non_snake_case)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Locale {
+    /// 英語（既定値）。
+    En,
+    /// 日本語。
+    Ja,
+    /// スペイン語。
+    Es,
+    /// フランス語。
+    Fr,
+}
+
+/// ロケールコード文字列から `Locale` を解決します。
+///
+/// [重要] `--lang <code>` フラグや `LANG` 環境変数（例: `ja_JP.UTF-8`）の値を
+/// そのまま受け取り、`_`/`-`/`.` より前の部分（大文字小文字は区別しない）で判定します。
+/// [重要] `""`（`LANG` が空文字列で設定されている場合）や `"C"`/`"POSIX"`
+/// （コンテナ/CI でよく使われる最小ロケール）は「未指定」として `Locale::En` に倒します。
+/// 理由: これらは実運用で頻出する値であり、未知のロケールコードとしてエラーにすると
+/// 最小環境で `cargo run -- [name]` が動かなくなってしまうため。
+///
+/// ## 引数
+/// - `code`: ロケールコード（例: `"ja"`, `"en_US"`, `"C"`, `""`）
+///
+/// ## 戻り値
+/// - `Ok(Locale)`: 解決できたロケール
+/// - `Err(AppError::InvalidLocale)`: 未知のロケールコード
+#[allow(non_snake_case)]
+pub fn localeFromCode(code: &str) -> Result<Locale, AppError> {
+    let normalized = code.trim().to_lowercase();
+    let primaryTag = normalized.split(['_', '-', '.']).next().unwrap_or("");
+
+    match primaryTag {
+        "" | "c" | "posix" => Ok(Locale::En),
+        "en" => Ok(Locale::En),
+        "ja" => Ok(Locale::Ja),
+        "es" => Ok(Locale::Es),
+        "fr" => Ok(Locale::Fr),
+        _ => Err(AppError::InvalidLocale {
+            code: code.to_string(),
+            supported: "en, ja, es, fr",
+        }),
+    }
+}
+
+/// `locale` と `name` から挨拶文を組み立てます。
+///
+/// ## 引数
+/// - `locale`: 挨拶の言語
+/// - `name`: 挨拶の対象者名
+///
+/// ## 戻り値
+/// - 挨拶文（例: `"Hello, World!"`, `"こんにちは、World!"`）
+#[allow(non_snake_case)]
+pub fn greeting(locale: Locale, name: &str) -> String {
+    match locale {
+        Locale::En => format!("Hello, {}!", name),
+        Locale::Ja => format!("こんにちは、{}!", name),
+        Locale::Es => format!("¡Hola, {}!", name),
+        Locale::Fr => format!("Bonjour, {}!", name),
+    }
+}
@@ -6,91 +6,75 @@
 //!
 //! ## 仕様
 //! - `cargo run -- [name]` で挨拶を表示します。
-//! - 引数が2個以上（name を複数指定）はエラーにします。
-//!
-//! ## 制限事項
-//! - 国際化(i18n)は未対応（学習用のため）。
-
-use std::error::Error;
-use std::fmt;
-
-/// 引数が仕様に合わない場合のエラー。
-///
-/// [重要] Rust の慣習は snake_case ですが、このリポジトリの命名規則（lowerCamelCase）を優先するため
-/// `non_snake_case` の警告を抑止します。理由: リポジトリ内の命名を統一するため。
-#[allow(non_snake_case)]
-#[derive(Debug, Clone)]
-pub struct InvalidArgumentsError {
-    /// エラーが発生した関数名。
-    pub functionName: &'static str,
+//! - `-n <name>` / `--name <name>` / `--name=<name>` でも name を指定できます。
+//! - `--file <path>` で、ファイル中の非空行ごとに挨拶を表示します。
+//! - `--lang <code>` または `LANG` 環境変数でロケール（en/ja/es/fr）を切り替えられます。
+//! - `--times <n>` で挨拶を `n` 回繰り返します（既定値: 1）。
+//! - `-h`/`--help` で使い方を表示します。
+//! - name の重複指定や未知のフラグはエラーにします。
+//! - 引数が不正な UTF-8 を含む場合も panic せず、詳細メッセージ付きのエラーを返します。
 
-    /// 受け取った引数一覧（プログラム名を含む）。
-    pub args: Vec<String>,
+use std::ffi::OsString;
 
-    /// 期待していた引数の形式。
-    pub expectedUsage: &'static str,
-}
-
-impl fmt::Display for InvalidArgumentsError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "Invalid arguments in {functionName}.\n  expected: {expectedUsage}\n  actual args({argc}): {args:?}",
-            functionName = self.functionName,
-            expectedUsage = self.expectedUsage,
-            argc = self.args.len(),
-            args = self.args
-        )
-    }
-}
+mod config;
+mod error;
+mod flags;
+mod greeting;
 
-impl Error for InvalidArgumentsError {}
+use config::Config;
+use error::AppError;
 
-/// コマンドライン引数から表示名(name)を取り出します。
+/// `std::env::args_os()` の結果を UTF-8 検証済みの `Vec<String>` に変換します。
 ///
-/// [重要] name は 0〜1 個のみ許可します。
+/// [重要] `std::env::args().collect()` は引数に不正な UTF-8 が含まれると panic しますが、
+/// この関数はその場合も panic せず `AppError::InvalidUtf8Arg` を返します。
+/// 理由: 不正な入力でプログラムを異常終了させないため。
 ///
 /// ## 引数
-/// - `args`: `std::env::args()` の結果を `Vec<String>` にしたもの（プログラム名を含む）
+/// - `args`: `std::env::args_os()` の結果を `Vec<OsString>` にしたもの（プログラム名を含む）
 ///
 /// ## 戻り値
-/// - `Ok(String)`: 表示名（未指定なら "World"）
-/// - `Err(InvalidArgumentsError)`: 引数が2個以上指定された等、仕様違反
+/// - `Ok(Vec<String>)`: UTF-8 に変換済みの引数一覧
+/// - `Err(AppError::InvalidUtf8Arg)`: 不正な UTF-8 を含む引数があった場合
 #[allow(non_snake_case)]
-pub fn parseNameFromArgs(args: &[String]) -> Result<String, InvalidArgumentsError> {
-    const FUNCTION_NAME: &str = "parseNameFromArgs";
-    const EXPECTED_USAGE: &str = "cargo run -- [name]";
+fn toUtf8Args(args: &[OsString]) -> Result<Vec<String>, AppError> {
+    let mut converted: Vec<String> = Vec::with_capacity(args.len());
 
-    // args[0] はプログラム名
-    // args[1] が name
-    // args[2] 以降がある場合は仕様違反
-    if args.len() >= 3 {
-        return Err(InvalidArgumentsError {
-            functionName: FUNCTION_NAME,
-            args: args.to_vec(),
-            expectedUsage: EXPECTED_USAGE,
-        });
+    for arg in args {
+        match arg.to_str() {
+            Some(value) => converted.push(value.to_string()),
+            None => {
+                return Err(AppError::InvalidUtf8Arg {
+                    lossy: arg.to_string_lossy().into_owned(),
+                });
+            }
+        }
     }
 
-    let name = match args.get(1) {
-        Some(value) if !value.trim().is_empty() => value.clone(),
-        _ => "World".to_string(),
-    };
-
-    Ok(name)
+    Ok(converted)
 }
 
 /// エントリポイント。
 ///
 /// [重要] エラー発生時は標準エラーに詳細を出力します。
-pub fn main() -> Result<(), Box<dyn Error>> {
-    let args: Vec<String> = std::env::args().collect();
+/// [重要] `args_os` 経由で引数を取得し、不正な UTF-8 でも panic しないようにします。
+/// [重要] `main`/`run` を分離し、`Config` の組み立てと実際の処理を切り分けます。
+#[allow(non_snake_case)]
+pub fn main() -> Result<(), AppError> {
+    let argsOs: Vec<OsString> = std::env::args_os().collect();
+
+    let args = toUtf8Args(&argsOs).map_err(|error| {
+        eprintln!("[ERROR] {}", error);
+        error
+    })?;
 
-    let name = parseNameFromArgs(&args).map_err(|error| {
+    let config = Config::build(&args).map_err(|error| {
         eprintln!("[ERROR] {}", error);
         error
     })?;
 
-    println!("Hello, {}!", name);
-    Ok(())
+    config::run(&config).map_err(|error| {
+        eprintln!("[ERROR] {}", error);
+        error
+    })
 }